@@ -1,4 +1,33 @@
 use super::fractal::FractalResult;
+use num::complex::Complex;
+use std::str::FromStr;
+
+/// Smooth (continuous) escape-time colouring of a given vector `input` of
+/// results, scaled against the `limit` iterations the render was given.
+///
+/// Rather than quantizing on the raw iteration count, which produces visible
+/// banding, this computes the normalized iteration count `mu` for each escaped
+/// point: `mu = n + 1 - ln(ln|z|) / ln(2)`, where `n` is the iteration at which
+/// the point escaped and `z` is its final value. `mu` varies continuously
+/// across the escape boundary. Interior points (`escape == 0`) map to 0;
+/// escaped points are scaled by `limit` so the full `0..=255` output range is
+/// used regardless of how many iterations the render allowed.
+pub fn smooth_color(input: &Vec<FractalResult>, limit: u32) -> Vec<u8> {
+    let output: Vec<u8> = input
+        .into_iter()
+        .map(|result| match result.escape {
+            0 => 0u8,
+            n => {
+                let mu = n as f64 + 1.0 - (result.value.norm().ln().ln() / 2f64.ln());
+                (mu / limit as f64 * std::u8::MAX as f64)
+                    .max(0.0)
+                    .min(std::u8::MAX as f64) as u8
+            }
+        })
+        .collect();
+
+    output
+}
 
 /// Gathers a binary decomposition colouring of a given vector `input` of results.
 ///
@@ -40,3 +69,192 @@ pub fn standard_color(input: &Vec<FractalResult>, mode: StandardColors) -> Vec<u
 
     output
 }
+
+/// Named RGB gradients usable with `color_rgb`.
+#[derive(Copy, Clone)]
+pub enum Palette {
+    /// Classic black -> red -> yellow -> white "fire" ramp.
+    FIRE,
+    /// Full-saturation hue sweep, cycling through the colour wheel.
+    HSV,
+}
+
+impl FromStr for Palette {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        match s {
+            "fire" => Ok(Palette::FIRE),
+            "hsv" => Ok(Palette::HSV),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Palette {
+    /// Map a normalized value in `0.0..=1.0` to an RGB colour.
+    pub fn color(&self, t: f64) -> [u8; 3] {
+        let t = t.max(0.0).min(1.0);
+        match self {
+            Palette::FIRE => fire_gradient(t),
+            Palette::HSV => hsv_to_rgb(t * 360.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// Black -> red -> yellow -> white ramp, in three equal segments.
+fn fire_gradient(t: f64) -> [u8; 3] {
+    if t < 1.0 / 3.0 {
+        let u = t * 3.0;
+        [(u * 255.0) as u8, 0, 0]
+    } else if t < 2.0 / 3.0 {
+        let u = (t - 1.0 / 3.0) * 3.0;
+        [255, (u * 255.0) as u8, 0]
+    } else {
+        let u = (t - 2.0 / 3.0) * 3.0;
+        [255, 255, (u * 255.0) as u8]
+    }
+}
+
+/// Convert an HSV colour (hue in degrees, saturation/value in `0.0..=1.0`) to RGB.
+fn hsv_to_rgb(h: f64, s: f64, v: f64) -> [u8; 3] {
+    let h = h.rem_euclid(360.0);
+    let c = v * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = v - c;
+    let (r1, g1, b1) = match (h / 60.0) as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    [
+        ((r1 + m) * 255.0).round() as u8,
+        ((g1 + m) * 255.0).round() as u8,
+        ((b1 + m) * 255.0).round() as u8,
+    ]
+}
+
+/// Basin-of-attraction colouring for Newton fractal results.
+///
+/// Classifies each result's final `value` by the nearest of the three cube
+/// roots of unity (`1`, `-0.5 + i*sqrt(3)/2`, `-0.5 - i*sqrt(3)/2`), assigning
+/// a distinct base hue per root, then darkens that hue proportionally to how
+/// many of the `limit` iterations it took to converge: fast convergence is
+/// bright, slow convergence near a basin boundary is dark. Points that never
+/// converged (`escape == 0`) are black.
+pub fn newton_basin_color(input: &Vec<FractalResult>, limit: u32) -> Vec<u8> {
+    const ROOTS: [Complex<f64>; 3] = [
+        Complex { re: 1.0, im: 0.0 },
+        Complex {
+            re: -0.5,
+            im: 0.866_025_403_784_438_6,
+        },
+        Complex {
+            re: -0.5,
+            im: -0.866_025_403_784_438_6,
+        },
+    ];
+    const HUES: [f64; 3] = [0.0, 120.0, 240.0];
+
+    let mut output = Vec::with_capacity(input.len() * 3);
+    for result in input {
+        if result.escape == 0 {
+            output.extend_from_slice(&[0, 0, 0]);
+            continue;
+        }
+
+        let (root, _) = ROOTS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (result.value - **a)
+                    .norm_sqr()
+                    .partial_cmp(&(result.value - **b).norm_sqr())
+                    .unwrap()
+            })
+            .unwrap();
+
+        let brightness = 1.0 - (result.escape as f64 / limit as f64).min(1.0);
+        output.extend_from_slice(&hsv_to_rgb(HUES[root], 1.0, brightness.max(0.15)));
+    }
+
+    output
+}
+
+/// RGB colouring of a given vector `input` of results using `palette`.
+///
+/// Each result is reduced to the same normalized smooth iteration count used
+/// by `smooth_color`, scaled against the highest escape value present in
+/// `input`, then mapped through `palette`. Produces interleaved RGB bytes.
+pub fn color_rgb(input: &Vec<FractalResult>, palette: Palette) -> Vec<u8> {
+    let max_escape = input.iter().map(|r| r.escape).max().unwrap_or(1).max(1) as f64;
+
+    let mut output = Vec::with_capacity(input.len() * 3);
+    for result in input {
+        let t = match result.escape {
+            0 => 0.0,
+            n => {
+                let mu = n as f64 + 1.0 - (result.value.norm().ln().ln() / 2f64.ln());
+                mu / max_escape
+            }
+        };
+        output.extend_from_slice(&palette.color(t));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fire_gradient_endpoints_and_peak() {
+        assert_eq!(fire_gradient(0.0), [0, 0, 0]);
+        assert_eq!(fire_gradient(1.0 / 3.0), [255, 0, 0]);
+        assert_eq!(fire_gradient(1.0), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_hsv_to_rgb_primary_hues() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(120.0, 1.0, 1.0), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb(240.0, 1.0, 1.0), [0, 0, 255]);
+    }
+
+    #[test]
+    fn test_newton_basin_color_classifies_nearest_root() {
+        let input = vec![
+            FractalResult {
+                escape: 1,
+                value: Complex::new(1.0, 0.0),
+            },
+            FractalResult {
+                escape: 1,
+                value: Complex::new(-0.5, 0.866_025_403_784_438_6),
+            },
+            FractalResult {
+                escape: 1,
+                value: Complex::new(-0.5, -0.866_025_403_784_438_6),
+            },
+            FractalResult {
+                escape: 0,
+                value: Complex::new(0.0, 0.0),
+            },
+        ];
+        let colors = newton_basin_color(&input, 100);
+
+        // Root `1` -> red-dominant hue.
+        assert!(colors[0] > colors[1] && colors[0] > colors[2]);
+        // Root `-0.5 + i*sqrt(3)/2` -> green-dominant hue.
+        assert!(colors[4] > colors[3] && colors[4] > colors[5]);
+        // Root `-0.5 - i*sqrt(3)/2` -> blue-dominant hue.
+        assert!(colors[8] > colors[6] && colors[8] > colors[7]);
+        // Never converged -> black.
+        assert_eq!(&colors[9..12], &[0, 0, 0]);
+    }
+}
@@ -1,8 +1,20 @@
 use num::complex::Complex;
+use rayon::prelude::*;
 use std::str::FromStr;
 
+/// Escape radius (squared) used by the bailout-based fractals.
+///
+/// Raised well above the classic `4.0` so that `ln(ln|z|)` in the smooth
+/// colouring formula is well-behaved once a point has escaped.
+const BAILOUT_SQR: f64 = 256.0;
+
+/// Extra iterations to run past the bailout point so the smooth colouring
+/// formula (which is only an approximation right at escape) has settled.
+const SMOOTH_EXTRA_ITERATIONS: u32 = 4;
+
 /// Result given from a fractal calculation.
-/// `escape`: Iterations needed to escape the function, 0 if it did not escape.
+/// `escape`: The iteration at which the function escaped (for Newton, the
+/// iteration at which it converged), 0 if it did not escape (or converge).
 /// `value`: Final z value, whether it escaped or not.
 #[derive(Clone, Debug)]
 pub struct FractalResult {
@@ -25,6 +37,9 @@ pub enum Fractal {
     MANDELBROT,
     JULIA,
     NEWTON,
+    BurningShip,
+    /// Generalized power Mandelbrot, `z = z^d + c`, with configurable integer exponent `d`.
+    MandelbrotD(u32),
 }
 
 impl FromStr for Fractal {
@@ -35,6 +50,8 @@ impl FromStr for Fractal {
             "mandelbrot" => Ok(Fractal::MANDELBROT),
             "julia" => Ok(Fractal::JULIA),
             "newton" => Ok(Fractal::NEWTON),
+            "burning_ship" => Ok(Fractal::BurningShip),
+            "mandelbrot3" => Ok(Fractal::MandelbrotD(3)),
             _ => Err(()),
         }
     }
@@ -47,6 +64,8 @@ impl Fractal {
             Fractal::MANDELBROT => mandelbrot(c, seed, limit),
             Fractal::JULIA => julia(c, seed, limit),
             Fractal::NEWTON => newton(c, seed, limit),
+            Fractal::BurningShip => burning_ship(c, seed, limit),
+            Fractal::MandelbrotD(d) => mandelbrot_d(c, seed, limit, *d),
         }
     }
 }
@@ -59,9 +78,12 @@ fn mandelbrot(c: Complex<f64>, seed: Complex<f64>, limit: u32) -> FractalResult
     let mut z = seed;
     for i in 0..limit {
         z = z * z + c;
-        if z.norm_sqr() > 4.0 {
+        if z.norm_sqr() > BAILOUT_SQR {
+            for _ in 0..SMOOTH_EXTRA_ITERATIONS {
+                z = z * z + c;
+            }
             return FractalResult {
-                escape: limit - i,
+                escape: i + 1,
                 value: z,
             };
         }
@@ -79,9 +101,66 @@ fn julia(start: Complex<f64>, seed: Complex<f64>, limit: u32) -> FractalResult {
     let mut z = start;
     for i in 0..limit {
         z = z * z + seed;
-        if z.norm_sqr() > 4.0 {
+        if z.norm_sqr() > BAILOUT_SQR {
+            for _ in 0..SMOOTH_EXTRA_ITERATIONS {
+                z = z * z + seed;
+            }
             return FractalResult {
-                escape: limit - i,
+                escape: i + 1,
+                value: z,
+            };
+        }
+    }
+
+    FractalResult {
+        escape: 0,
+        value: z,
+    }
+}
+
+/// Try to determine if `c` is in the Burning Ship set, using
+/// at most `limit` iterations.
+///
+/// Like `mandelbrot`, but the real and imaginary parts of `z` are folded to
+/// their absolute value before squaring on each iteration, i.e.
+/// `z = (|Re z| + i*|Im z|)^2 + c`.
+fn burning_ship(c: Complex<f64>, seed: Complex<f64>, limit: u32) -> FractalResult {
+    let mut z = seed;
+    for i in 0..limit {
+        z = Complex::new(z.re.abs(), z.im.abs());
+        z = z * z + c;
+        if z.norm_sqr() > BAILOUT_SQR {
+            for _ in 0..SMOOTH_EXTRA_ITERATIONS {
+                z = Complex::new(z.re.abs(), z.im.abs());
+                z = z * z + c;
+            }
+            return FractalResult {
+                escape: i + 1,
+                value: z,
+            };
+        }
+    }
+
+    FractalResult {
+        escape: 0,
+        value: z,
+    }
+}
+
+/// Try to determine if `c` is in the generalized power-`d` Mandelbrot set,
+/// using at most `limit` iterations.
+///
+/// Iterates `z = z^d + c` instead of the usual `z = z^2 + c`.
+fn mandelbrot_d(c: Complex<f64>, seed: Complex<f64>, limit: u32, d: u32) -> FractalResult {
+    let mut z = seed;
+    for i in 0..limit {
+        z = z.powu(d) + c;
+        if z.norm_sqr() > BAILOUT_SQR {
+            for _ in 0..SMOOTH_EXTRA_ITERATIONS {
+                z = z.powu(d) + c;
+            }
+            return FractalResult {
+                escape: i + 1,
                 value: z,
             };
         }
@@ -102,7 +181,7 @@ fn newton(start: Complex<f64>, seed: Complex<f64>, limit: u32) -> FractalResult
         let bail = newz - z;
         if bail.norm_sqr() <= 0.00001 {
             return FractalResult {
-                escape: limit - i,
+                escape: i + 1,
                 value: newz,
             };
         };
@@ -140,6 +219,37 @@ pub fn parse_complex(s: &str) -> Option<Complex<f64>> {
     }
 }
 
+/// Render a full fractal image in parallel, returning one `FractalResult`
+/// per pixel of the `bounds.0 x bounds.1` output, in row-major order.
+///
+/// Uses rayon's work-stealing `par_chunks_mut` over per-row chunks rather
+/// than hand-splitting the image into fixed-size thread bands: pixels near
+/// the fractal boundary cost far more iterations than interior or exterior
+/// ones, so equal-size bands stall on whichever band has the most boundary,
+/// while rayon keeps every worker busy.
+pub fn render_parallel(
+    bounds: (usize, usize),
+    upper_left: Complex<f64>,
+    lower_right: Complex<f64>,
+    method: Fractal,
+    seed: Complex<f64>,
+    limit: u32,
+) -> Vec<FractalResult> {
+    let mut results = vec![FractalResult::zero(); bounds.0 * bounds.1];
+
+    results
+        .par_chunks_mut(bounds.0)
+        .enumerate()
+        .for_each(|(row, chunk)| {
+            for (col, result) in chunk.iter_mut().enumerate() {
+                let point = pixel_to_point(bounds, (col, row), upper_left, lower_right);
+                *result = method.calculate(point, seed, limit);
+            }
+        });
+
+    results
+}
+
 /// Given the row and column of a pixel in the output image, return the
 /// corresponding point on the complex plane.
 ///
@@ -204,4 +314,43 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_fractal_from_str_new_variants() {
+        assert!(matches!(
+            Fractal::from_str("burning_ship"),
+            Ok(Fractal::BurningShip)
+        ));
+        assert!(matches!(
+            Fractal::from_str("mandelbrot3"),
+            Ok(Fractal::MandelbrotD(3))
+        ));
+    }
+
+    #[test]
+    fn test_burning_ship_interior_stays_zero() {
+        let result = burning_ship(Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), 50);
+        assert_eq!(result.escape, 0);
+        assert_eq!(result.value, Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_burning_ship_escapes_far_point() {
+        let result = burning_ship(Complex::new(10.0, 10.0), Complex::new(0.0, 0.0), 50);
+        assert!(result.escape > 0);
+        assert!(result.value.norm_sqr() > BAILOUT_SQR);
+    }
+
+    #[test]
+    fn test_mandelbrot_d_interior_stays_zero() {
+        let result = mandelbrot_d(Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), 50, 3);
+        assert_eq!(result.escape, 0);
+        assert_eq!(result.value, Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_mandelbrot_d_escapes_far_point() {
+        let result = mandelbrot_d(Complex::new(10.0, 10.0), Complex::new(0.0, 0.0), 50, 3);
+        assert!(result.escape > 0);
+        assert!(result.value.norm_sqr() > BAILOUT_SQR);
+    }
 }
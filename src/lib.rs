@@ -0,0 +1,74 @@
+//! Core fractal rendering engine.
+//!
+//! This crate is the single source of truth for the iteration loop and
+//! colouring functions, so it can be driven either by the `fractal` PNG CLI
+//! or embedded directly (e.g. a WASM in-browser viewer) without duplicating
+//! the render logic.
+
+use num::complex::Complex;
+
+mod coloring;
+mod fractal;
+
+pub use coloring::*;
+pub use fractal::*;
+
+/// Render a full fractal image, returning one `FractalResult` per pixel of
+/// the `bounds.0 x bounds.1` output, in row-major order.
+pub fn render(
+    bounds: (usize, usize),
+    upper_left: Complex<f64>,
+    lower_right: Complex<f64>,
+    method: Fractal,
+    seed: Complex<f64>,
+    limit: u32,
+) -> Vec<FractalResult> {
+    render_parallel(bounds, upper_left, lower_right, method, seed, limit)
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod wasm {
+    use super::*;
+    use std::str::FromStr;
+    use wasm_bindgen::prelude::*;
+
+    /// Render a fractal directly to an RGBA buffer, suitable for writing
+    /// straight into a canvas `ImageData` from a WASM-hosted viewer.
+    ///
+    /// `method` and `palette_name` are the same strings accepted by the
+    /// native CLI (e.g. `"mandelbrot"`/`"julia"`/`"newton"` and
+    /// `"fire"`/`"hsv"`). Newton renders always use basin-of-attraction
+    /// colouring, regardless of `palette_name`.
+    #[wasm_bindgen]
+    pub fn render_rgba(
+        width: usize,
+        height: usize,
+        upper_left_re: f64,
+        upper_left_im: f64,
+        lower_right_re: f64,
+        lower_right_im: f64,
+        method: &str,
+        seed_re: f64,
+        seed_im: f64,
+        limit: u32,
+        palette_name: &str,
+    ) -> Vec<u8> {
+        let bounds = (width, height);
+        let upper_left = Complex::new(upper_left_re, upper_left_im);
+        let lower_right = Complex::new(lower_right_re, lower_right_im);
+        let seed = Complex::new(seed_re, seed_im);
+        let method = Fractal::from_str(method).unwrap_or(Fractal::MANDELBROT);
+
+        let results = render(bounds, upper_left, lower_right, method, seed, limit);
+
+        let rgb = match method {
+            Fractal::NEWTON => newton_basin_color(&results, limit),
+            _ => {
+                let palette = Palette::from_str(palette_name).unwrap_or(Palette::FIRE);
+                color_rgb(&results, palette)
+            }
+        };
+
+        rgb.chunks(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect()
+    }
+}
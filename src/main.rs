@@ -5,36 +5,32 @@ use std::fs::File;
 use std::io::Write;
 use std::str::FromStr;
 
-mod fractal;
-use fractal::*;
-
-mod coloring;
-use coloring::*;
+use fractal_rust::*;
 
 /// Write the given buffer of `pixels`, with dimensions `bounds` into the file `filename`.
+///
+/// `color` selects the PNG pixel format; `pixels` must already be laid out to
+/// match it (one byte per pixel for `ColorType::Gray(8)`, three interleaved
+/// bytes per pixel for `ColorType::RGB(8)`).
 fn write_image(
     filename: &str,
     pixels: &[u8],
     bounds: (usize, usize),
+    color: ColorType,
 ) -> Result<(), std::io::Error> {
     let output = File::create(filename)?;
     let encoder = PNGEncoder::new(output);
-    encoder.encode(
-        &pixels,
-        bounds.0 as u32,
-        bounds.1 as u32,
-        ColorType::Gray(8),
-    )?;
+    encoder.encode(&pixels, bounds.0 as u32, bounds.1 as u32, color)?;
 
     Ok(())
 }
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
-    if args.len() != 8 {
+    if args.len() != 8 && args.len() != 9 {
         writeln!(
             std::io::stderr(),
-            "Usage: fractal FILE METHOD PIXELS UPPERLEFT LOWERRIGHT SEED LIMIT"
+            "Usage: fractal FILE METHOD PIXELS UPPERLEFT LOWERRIGHT SEED LIMIT [PALETTE]"
         )
         .unwrap();
         writeln!(
@@ -45,10 +41,15 @@ fn main() {
         .unwrap();
         writeln!(
             std::io::stderr(),
-            "Example: {} julia.png julia 1000x750 -1.50,1 1.5,-1 -0.8,0.156 255",
+            "Example: {} julia.png julia 1000x750 -1.50,1 1.5,-1 -0.8,0.156 255 fire",
             args[0]
         )
         .unwrap();
+        writeln!(
+            std::io::stderr(),
+            "PALETTE is optional; omit it for grayscale output, or pass \"fire\" or \"hsv\" for RGB output. Newton renders are always RGB basin-of-attraction colouring."
+        )
+        .unwrap();
         std::process::exit(1);
     }
     let method = Fractal::from_str(&args[2]).expect("error parsing fractal method");
@@ -57,51 +58,24 @@ fn main() {
     let lower_right = parse_complex(&args[5]).expect("error parsing lower right corner point");
     let seed = parse_complex(&args[6]).expect("error parsing seeded value");
     let limit = u32::from_str(&args[7]).expect("error parsing limit");
+    let palette = args
+        .get(8)
+        .map(|name| Palette::from_str(name).expect("error parsing palette name"));
 
     // Output results we're going to use to render to an image
-    let mut results = vec![FractalResult::zero(); bounds.0 * bounds.1];
-
-    // Spawning threads based on available CPUs
-    let threads = num_cpus::get();
-    let rows_per_band = bounds.1 / threads + 1;
-    {
-        let bands: Vec<&mut [FractalResult]> =
-            results.chunks_mut(rows_per_band * bounds.0).collect();
-        match crossbeam::scope(|spawner| {
-            for (i, band) in bands.into_iter().enumerate() {
-                let top = rows_per_band * i;
-                let height = band.len() / bounds.0;
-                let band_bounds = (bounds.0, height);
-                let band_upper_left = pixel_to_point(bounds, (0, top), upper_left, lower_right);
-                let band_lower_right =
-                    pixel_to_point(bounds, (bounds.0, top + height), upper_left, lower_right);
-
-                spawner.spawn(move |_| {
-                    render_to_result(
-                        band,
-                        band_bounds,
-                        band_upper_left,
-                        band_lower_right,
-                        method,
-                        seed,
-                        limit,
-                    );
-                });
-            }
-        }) {
-            Err(e) => {
-                eprintln!("Error: {:?}", e);
-                std::process::exit(1);
-            }
-            Ok(_) => (),
-        };
-    }
+    let results = render(bounds, upper_left, lower_right, method, seed, limit);
 
-    // Convert our results into a pixels array to draw. Just draw the escape value for the default function.
-    let pixels: Vec<u8> = results.into_iter().map(|res| res.escape as u8).collect();
+    // Newton fractals are meaningless under escape-time colouring, since every
+    // point "escapes" by converging; colour them by basin of attraction instead.
+    // Otherwise, render RGB if a palette was requested, or plain grayscale.
+    let (pixels, color_type) = match (method, palette) {
+        (Fractal::NEWTON, _) => (newton_basin_color(&results, limit), ColorType::RGB(8)),
+        (_, Some(palette)) => (color_rgb(&results, palette), ColorType::RGB(8)),
+        (_, None) => (smooth_color(&results, limit), ColorType::Gray(8)),
+    };
     // Alternatively, use a coloring method on a set of the results.
     // let pixels: Vec<u8> = binary_decomposition(&results);
     // let pixels: Vec<u8> = standard_color(&results, StandardColors::SUM);
 
-    write_image(&args[1], &pixels, bounds).expect("error writing PNG file");
+    write_image(&args[1], &pixels, bounds, color_type).expect("error writing PNG file");
 }